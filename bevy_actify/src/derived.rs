@@ -0,0 +1,156 @@
+//! SPDX-License-Identifier: MIT OR Apache-2.0
+//!
+//! Derived and chord input actions, computed from the state of
+//! other already-registered actions, see
+//! [`DerivedInputActionPlugin`].
+
+use std::{marker::PhantomData, sync::Arc};
+
+use bevy::{
+    app::{App, Last, Plugin, PreUpdate},
+    ecs::{schedule::IntoSystemConfigs, world::World},
+};
+
+use crate::{
+    internal,
+    plugin::{
+        clear_input_action_drain, configure_input_action_sets, update_input_action_state,
+        write_input_action_events, InputActionProcessors, InputActionSystem,
+    },
+    InputAction,
+};
+
+/// Adds a derived (or chord) action, computed every frame from the
+/// current-frame state of other already-registered actions.
+///
+/// `Inputs` is a tuple of the [`InputAction`] types to read, e.g.
+/// `(Sprint, Jump)`. The combinator is called with one `Option<I>`
+/// per input (`Some` when that action is currently `Active`), and
+/// its return value is poured into `A`'s [`InputActionDrain`](crate::InputActionDrain)
+/// — returning `None` leaves `A` `Idle` for the frame, same as an
+/// empty drain.
+///
+/// By default, the combinator runs in [`InputActionSystem::Derived`],
+/// so it can depend on `Primitive` actions. No order is set between
+/// two `Derived` actions, so reading another `Derived` action may see
+/// its state from the previous frame. Use [`as_chord`](Self::as_chord)
+/// if it needs to depend on a `Derived` action instead.
+///
+/// ### Example
+/// ```ignore
+/// app.add_plugins(DerivedInputActionPlugin::<Dash, (Sprint, Jump)>::new(
+///     |(sprint, jump)| (sprint.is_some() && jump.is_some()).then_some(Dash),
+/// ));
+/// ```
+pub struct DerivedInputActionPlugin<A: InputAction, Inputs: DerivedInputs> {
+    combinator: Arc<dyn Fn(Inputs::Values) -> Option<A> + Send + Sync>,
+    chord: bool,
+    _marker: PhantomData<(A, Inputs)>,
+}
+
+impl<A: InputAction, Inputs: DerivedInputs> DerivedInputActionPlugin<A, Inputs> {
+    /// Returns a new derived input action plugin for `A`, combining
+    /// the current-frame state of `Inputs` with `combinator`.
+    pub fn new(combinator: impl Fn(Inputs::Values) -> Option<A> + Send + Sync + 'static) -> Self {
+        Self {
+            combinator: Arc::new(combinator),
+            chord: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Runs this action's combinator in [`InputActionSystem::Chord`]
+    /// instead of `::Derived`, so it may read the current-frame state
+    /// of `Derived` actions in `Inputs` (e.g. building a chord out of
+    /// two already-derived composite actions), not just `Primitive`
+    /// ones.
+    pub fn as_chord(mut self) -> Self {
+        self.chord = true;
+        self
+    }
+}
+
+impl<A: InputAction, Inputs: DerivedInputs> Plugin for DerivedInputActionPlugin<A, Inputs> {
+    fn build(&self, app: &mut App) {
+        configure_input_action_sets(app);
+
+        app.init_resource::<internal::InputActionState<A>>();
+        app.init_resource::<internal::InputActionDrain<A>>();
+        app.insert_resource(InputActionProcessors::<A>::empty());
+
+        app.add_event::<internal::InputActionUpdated<A>>();
+
+        let set = if self.chord {
+            InputActionSystem::Chord
+        } else {
+            InputActionSystem::Derived
+        };
+
+        let combinator = self.combinator.clone();
+        app.add_systems(
+            PreUpdate,
+            (move |world: &mut World| {
+                if let Some(value) = combinator(Inputs::read(world)) {
+                    world
+                        .resource_mut::<internal::InputActionDrain<A>>()
+                        .replace(value);
+                }
+            })
+            .in_set(set.clone())
+            .before(update_input_action_state::<A>),
+        );
+
+        app.add_systems(
+            PreUpdate,
+            (
+                update_input_action_state::<A>,
+                write_input_action_events::<A>,
+            )
+                .chain()
+                .in_set(set),
+        );
+        app.add_systems(Last, clear_input_action_drain::<A>);
+    }
+}
+
+/// Implemented for tuples of [`InputAction`] types that a
+/// [`DerivedInputActionPlugin`] can read together.
+///
+/// Implemented for tuples of up to 8 elements; not meant to be
+/// implemented outside this crate.
+pub trait DerivedInputs: Send + Sync + 'static {
+    /// One `Option<I>` per input type, `Some` when that action is
+    /// currently `Active`.
+    type Values;
+
+    /// Reads the current-frame state of every input out of `world`.
+    fn read(world: &World) -> Self::Values;
+}
+
+fn read_input<I: InputAction>(world: &World) -> Option<I> {
+    match world.get_resource::<internal::InputActionState<I>>()? {
+        internal::InputActionState::Active(value) => Some(value.clone()),
+        internal::InputActionState::Idle => None,
+    }
+}
+
+macro_rules! impl_derived_inputs {
+    ($($input:ident),+) => {
+        impl<$($input: InputAction),+> DerivedInputs for ($($input,)+) {
+            type Values = ($(Option<$input>,)+);
+
+            fn read(world: &World) -> Self::Values {
+                ($(read_input::<$input>(world),)+)
+            }
+        }
+    };
+}
+
+impl_derived_inputs!(I1);
+impl_derived_inputs!(I1, I2);
+impl_derived_inputs!(I1, I2, I3);
+impl_derived_inputs!(I1, I2, I3, I4);
+impl_derived_inputs!(I1, I2, I3, I4, I5);
+impl_derived_inputs!(I1, I2, I3, I4, I5, I6);
+impl_derived_inputs!(I1, I2, I3, I4, I5, I6, I7);
+impl_derived_inputs!(I1, I2, I3, I4, I5, I6, I7, I8);
@@ -1,11 +1,18 @@
+pub mod derived;
 pub mod plugin;
+pub mod processor;
 
+pub use derived::DerivedInputActionPlugin;
 pub use plugin::{InputActionPlugin, InputActionSystem};
 
 #[cfg(feature = "derive")]
 pub use bevy_actify_derive::InputAction;
 
-use bevy::ecs::system::{Res, ResMut, SystemParam};
+use bevy::ecs::{
+    event::EventReader,
+    system::{Res, ResMut, SystemParam},
+    world::World,
+};
 
 /// Provides read-only access to the current state of an
 /// input action.
@@ -42,8 +49,12 @@ pub struct InputActionState<'w, A: InputAction> {
 /// - The drain only retains the **most recent state** poured
 ///   into it.
 /// - Any previously poured value is overwritten by the new value.
-/// - The drain is cleared every frame after its value is read
-///   to update the [`InputActionState`].
+/// - The drain is only **peeked** while resolving the
+///   [`InputActionState`], so that later schedules in the same
+///   frame (e.g. `FixedPreUpdate`, under
+///   [`InputActionPlugin::in_fixed_update`]) still observe this
+///   frame's value. It's cleared once per frame, in `Last`, after
+///   every such schedule has had a chance to observe it.
 ///
 /// ### Notes
 /// - If multiple systems pour state into the drain, only the
@@ -53,6 +64,60 @@ pub struct InputActionDrain<'w, A: InputAction> {
     inner: ResMut<'w, internal::InputActionDrain<A>>,
 }
 
+/// Represents the status of an input action as read
+/// from an [`InputActionReader`].
+///
+/// This enum describes the lifecycle of an input action,
+/// indicating whether it has just started, been updated,
+/// or stopped. It is typically used to react to changes
+/// in input state in a structured way.
+///
+/// ### Variants
+/// - **`Started(A)`**: The input action has transitioned
+///   from `Idle` to `Active`. This variant contains the
+///   current state of the input action (`A`).
+/// - **`Updated(A)`**: The input action was already `Active`,
+///   but its state has changed. This variant contains the
+///   updated state of the input action (`A`).
+/// - **`Stopped`**: The input action has transitioned from
+///   `Active` to `Idle`. This variant does not contain
+///   additional data, as the action is no longer active.
+#[derive(Debug)]
+pub enum InputActionStatus<'e, A: InputAction> {
+    /// The input action has started at this frame.
+    Started(&'e A),
+
+    /// The input action had been active, but changed
+    /// the value.
+    Updated(&'e A),
+
+    /// The input action has stopped at this frame.
+    Stopped,
+}
+
+/// Reader for input action status updates.
+///
+/// This system param provides an event-like way to react to
+/// changes in input actions.
+#[derive(SystemParam, Debug)]
+pub struct InputActionReader<'w, 's, A: InputAction> {
+    inner: EventReader<'w, 's, internal::InputActionUpdated<A>>,
+}
+
+/// Reader for input action status updates, from `FixedUpdate`.
+///
+/// Identical to [`InputActionReader`], except it reads the
+/// independent event channel written by the `FixedPreUpdate` pass
+/// registered via [`InputActionPlugin::in_fixed_update`]. Use this
+/// (instead of [`InputActionReader`]) in systems living in or after
+/// `FixedUpdate`, so a transition is observed exactly once per fixed
+/// tick it's relevant to, instead of racing the `Update`-facing
+/// channel.
+#[derive(SystemParam, Debug)]
+pub struct FixedInputActionReader<'w, 's, A: InputAction> {
+    inner: EventReader<'w, 's, internal::FixedInputActionUpdated<A>>,
+}
+
 pub trait InputAction: Send + Sync + Clone + PartialEq + 'static {}
 
 impl<'w, A: InputAction> InputActionState<'w, A> {
@@ -97,8 +162,12 @@ impl<'w, A: InputAction> InputActionDrain<'w, A> {
     /// - The drain only retains the **most recent state** that was
     ///   poured into it.
     /// - Any previously poured state is overwritten by the new state.
-    /// - The drain is cleared every frame after its state is read
-    ///   to update the `InputActionState`.
+    /// - The drain is only **peeked** while updating `InputActionState`,
+    ///   so that later schedules in the same frame (e.g.
+    ///   `FixedPreUpdate`, under [`InputActionPlugin::in_fixed_update`])
+    ///   still observe this frame's state. It's cleared once per
+    ///   frame, in `Last`, after every such schedule has had a chance
+    ///   to observe it.
     ///
     /// ### Notes
     /// - This method is typically called by systems that provide
@@ -109,6 +178,121 @@ impl<'w, A: InputAction> InputActionDrain<'w, A> {
     pub fn pour(&mut self, state: A) {
         self.inner.replace(state);
     }
+
+    /// Forces the action to resolve to [`InputActionState::Idle`]
+    /// this frame, even if something was (or still will be) poured
+    /// into the drain.
+    ///
+    /// Use this when a producing system stops running (its owning
+    /// entity despawns, an input context is disabled, ...) so the
+    /// action doesn't stay stuck `Active` forever. The normal event
+    /// pipeline still runs afterwards, so a proper `Stopped` event is
+    /// emitted if the action was `Active` last frame.
+    ///
+    /// See also [`reset_input_action`] for releasing an action from
+    /// outside a system (e.g. exclusive teardown code with direct
+    /// `World` access).
+    pub fn release(&mut self) {
+        self.inner.release();
+    }
+}
+
+impl<A: InputAction> InputActionReader<'_, '_, A> {
+    /// see [`EventReader::read`](bevy::ecs::event::EventReader::read).
+    pub fn read(&mut self) -> impl ExactSizeIterator<Item = InputActionStatus<A>> {
+        self.inner.read().map(|event| match event {
+            internal::InputActionUpdated::Started(state) => InputActionStatus::Started(state),
+            internal::InputActionUpdated::Updated(state) => InputActionStatus::Updated(state),
+            internal::InputActionUpdated::Stopped => InputActionStatus::Stopped,
+        })
+    }
+
+    /// Returns the values of every `Started` event read this call,
+    /// without cloning them.
+    pub fn started(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Started(value) => Some(value),
+            InputActionStatus::Updated(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// Returns the values of every `Updated` event read this call,
+    /// without cloning them.
+    pub fn updated(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Updated(value) => Some(value),
+            InputActionStatus::Started(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// see [`EventReader::is_empty`](bevy::ecs::event::EventReader::is_empty).
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// see [`EventReader::clear`](bevy::ecs::event::EventReader::clear).
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<A: InputAction> FixedInputActionReader<'_, '_, A> {
+    /// see [`EventReader::read`](bevy::ecs::event::EventReader::read).
+    pub fn read(&mut self) -> impl ExactSizeIterator<Item = InputActionStatus<A>> {
+        self.inner.read().map(|event| match event {
+            internal::FixedInputActionUpdated::Started(state) => InputActionStatus::Started(state),
+            internal::FixedInputActionUpdated::Updated(state) => InputActionStatus::Updated(state),
+            internal::FixedInputActionUpdated::Stopped => InputActionStatus::Stopped,
+        })
+    }
+
+    /// Returns the values of every `Started` event read this call,
+    /// without cloning them.
+    pub fn started(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Started(value) => Some(value),
+            InputActionStatus::Updated(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// Returns the values of every `Updated` event read this call,
+    /// without cloning them.
+    pub fn updated(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Updated(value) => Some(value),
+            InputActionStatus::Started(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// see [`EventReader::is_empty`](bevy::ecs::event::EventReader::is_empty).
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// see [`EventReader::clear`](bevy::ecs::event::EventReader::clear).
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+/// Releases the [`InputActionDrain`] for `A`, so it resolves to
+/// [`InputActionState::Idle`] the next time its state is resolved in
+/// the current frame, regardless of what's been poured into it. The
+/// release is cleared with the drain in `Last`, so when called after
+/// that resolution (e.g. from `Update`) it has no effect on later
+/// frames.
+///
+/// This is the one-off, imperative counterpart to
+/// [`InputActionDrain::release`], for call sites that aren't systems,
+/// such as a one-time cleanup when despawning the entity that owns
+/// `A`'s input sources.
+///
+/// Does nothing if `A` hasn't been registered with [`InputActionPlugin`]
+/// or [`DerivedInputActionPlugin`].
+pub fn reset_input_action<A: InputAction>(world: &mut World) {
+    if let Some(mut drain) = world.get_resource_mut::<internal::InputActionDrain<A>>() {
+        drain.release();
+    }
 }
 
 pub(crate) mod internal {
@@ -141,15 +325,20 @@ pub(crate) mod internal {
     /// are discarded.
     ///
     /// The value in the drain is used to update the [`InputActionState`]
-    /// at the end of each frame.
+    /// every frame.
     ///
     /// ### Behavior
     /// - If multiple systems write to the drain, only the
     ///   **last value** written will be used.
-    /// - The drain is automatically cleared after its value
-    ///   is read to update the [`InputActionState`].
+    /// - The drain is only **peeked** while updating
+    ///   [`InputActionState`], so that later schedules in the same
+    ///   frame (e.g. `FixedPreUpdate`) still observe this frame's
+    ///   value; it's explicitly cleared once per frame, in `Last`.
     #[derive(Resource, Debug)]
-    pub struct InputActionDrain<A: InputAction>(Option<A>);
+    pub struct InputActionDrain<A: InputAction> {
+        value: Option<A>,
+        released: bool,
+    }
 
     /// Input action update event.
     ///
@@ -162,6 +351,19 @@ pub(crate) mod internal {
         Stopped,
     }
 
+    /// Input action update event, for `FixedUpdate` readers.
+    ///
+    /// Same payload as [`InputActionUpdated`], but registered as its
+    /// own event channel so an [`crate::InputActionReader`] and a
+    /// [`crate::FixedInputActionReader`] for the same action never
+    /// double up on (or race for) the same transition.
+    #[derive(Event, Debug)]
+    pub enum FixedInputActionUpdated<A: InputAction> {
+        Started(A),
+        Updated(A),
+        Stopped,
+    }
+
     impl<A: InputAction> Default for InputActionState<A> {
         fn default() -> Self {
             Self::Idle
@@ -170,7 +372,10 @@ pub(crate) mod internal {
 
     impl<A: InputAction> Default for InputActionDrain<A> {
         fn default() -> Self {
-            Self(None)
+            Self {
+                value: None,
+                released: false,
+            }
         }
     }
 
@@ -178,13 +383,38 @@ pub(crate) mod internal {
         type Target = Option<A>;
 
         fn deref(&self) -> &Self::Target {
-            &self.0
+            &self.value
         }
     }
 
     impl<A: InputAction> DerefMut for InputActionDrain<A> {
         fn deref_mut(&mut self) -> &mut Self::Target {
-            &mut self.0
+            &mut self.value
+        }
+    }
+
+    impl<A: InputAction> InputActionDrain<A> {
+        /// Marks the drain as released, so every read this frame
+        /// forces [`InputActionState::Idle`] regardless of its value.
+        pub(crate) fn release(&mut self) {
+            self.released = true;
+        }
+
+        /// Returns whether the drain was released this frame.
+        pub(crate) fn released(&self) -> bool {
+            self.released
+        }
+
+        /// Clears the value and released flag for the next frame.
+        ///
+        /// Unlike the destructive reads this replaced, this is only
+        /// called once per frame (in `Last`), after every schedule
+        /// that resolves the drain this frame (`PreUpdate`, and
+        /// `FixedPreUpdate` under [`InputActionPlugin::in_fixed_update`])
+        /// has had a chance to observe it.
+        pub(crate) fn clear(&mut self) {
+            self.value = None;
+            self.released = false;
         }
     }
 }
@@ -1,36 +1,65 @@
-use std::marker::PhantomData;
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
 
 use bevy::{
-    app::{App, Plugin, PreUpdate},
+    app::{App, FixedPreUpdate, Last, Plugin, PreUpdate},
     ecs::{
         event::EventWriter,
-        schedule::{IntoSystemConfigs, SystemSet},
-        system::{Local, Res, ResMut},
+        schedule::{BoxedCondition, Condition, IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+        system::{IntoSystem, Local, Res, ResMut, Resource},
     },
 };
 
-use crate::{InputAction, InputActionUpdated, internal};
+use crate::{internal, InputAction};
 
-/// Label for systems that update input actions.
+/// Labels for the ordered sub-sets of systems that update
+/// input actions.
+///
+/// The variants run in declaration order, each strictly after
+/// the previous one:
+/// - **`Primitive`**: Actions poured directly by user input
+///   systems, registered via [`InputActionPlugin`].
+/// - **`Derived`**: Actions computed from already-resolved
+///   `Primitive` actions, registered via
+///   [`DerivedInputActionPlugin`](crate::derived::DerivedInputActionPlugin).
+/// - **`Chord`**: Actions computed from already-resolved
+///   `Derived` actions, registered via a
+///   [`DerivedInputActionPlugin`](crate::derived::DerivedInputActionPlugin)
+///   built with
+///   [`as_chord`](crate::derived::DerivedInputActionPlugin::as_chord).
 ///
 /// ### Usage
 /// - Those systems that provide input action state (i.e.,
 ///   pour into [`InputActionDrain`]) should be configured
-///   to run **before** this set.
+///   to run **before** the set of the action they pour into.
 /// - Those systems that read input action state should be
-///   configured to run **after** this set.
+///   configured to run **after** `InputActionSystem::Chord`.
 ///
 /// ### Notes
 /// Since all input action systems run in the `PreUpdate`
 /// stage, the systems that read input action state almost
-/// never have to be explicitly configured to run after this
-/// set, because they are most likely to run in the `Update`
+/// never have to be explicitly configured to run after these
+/// sets, because they are most likely to run in the `Update`
 /// schedule, which already runs *after*.
 #[derive(SystemSet, Hash, PartialEq, Eq, Clone, Debug)]
-pub struct InputActionSystem;
+pub enum InputActionSystem {
+    /// Actions poured directly by user input systems.
+    Primitive,
+
+    /// Actions computed from already-resolved `Primitive` actions.
+    Derived,
+
+    /// Actions computed from already-resolved `Derived` actions.
+    Chord,
+}
 
 /// Adds the input action to an app.
 pub struct InputActionPlugin<A: InputAction> {
+    fixed_update: bool,
+    processors: Vec<Arc<dyn Fn(A) -> Option<A> + Send + Sync>>,
+    auto_release: Mutex<Option<BoxedCondition>>,
     _marker: PhantomData<A>,
 }
 
@@ -38,9 +67,72 @@ impl<A: InputAction> InputActionPlugin<A> {
     /// Returns a new input action plugin.
     pub fn new() -> Self {
         Self {
+            fixed_update: false,
+            processors: Vec::new(),
+            auto_release: Mutex::new(None),
             _marker: PhantomData,
         }
     }
+
+    /// Appends a processor to the action's value-processing pipeline.
+    ///
+    /// Processors run in registration order on the value taken from
+    /// the [`InputActionDrain`], before it's resolved into
+    /// [`InputActionState`]. A processor that returns `None` collapses
+    /// the action to `Idle` for this frame and short-circuits the
+    /// rest of the pipeline, see [`crate::processor`] for built-ins
+    /// (deadzone, clamping, sensitivity) that fit this model.
+    pub fn with_processor(
+        mut self,
+        processor: impl Fn(A) -> Option<A> + Send + Sync + 'static,
+    ) -> Self {
+        self.processors.push(Arc::new(processor));
+        self
+    }
+
+    /// Enables FixedUpdate-correct `Started`/`Updated`/`Stopped`
+    /// tracking for this action.
+    ///
+    /// By default, an [`InputActionReader`](crate::InputActionReader)
+    /// only observes a lifecycle transition once per render frame,
+    /// which is wrong for systems living in `FixedUpdate`: when the
+    /// fixed schedule runs more than once per frame the
+    /// transition is only seen on one of the ticks, and when it doesn't run at
+    /// all a transition can be missed entirely.
+    ///
+    /// This re-resolves the [`InputActionState`] and registers a
+    /// second, independently-tracked event-writing pass in
+    /// `FixedPreUpdate`, writing into its own channel (read via
+    /// [`FixedInputActionReader`](crate::FixedInputActionReader)) so
+    /// fixed-timestep readers observe each transition once, however
+    /// many fixed ticks run in a frame, without racing (or doubling
+    /// up on) whatever an ordinary
+    /// [`InputActionReader`](crate::InputActionReader) observes from
+    /// `PreUpdate`.
+    ///
+    /// The pass only runs on frames with at least one fixed tick: an
+    /// action that turns on and off again within frames in which the
+    /// fixed schedule doesn't run is not observed by fixed readers.
+    pub fn in_fixed_update(mut self) -> Self {
+        self.fixed_update = true;
+        self
+    }
+
+    /// Automatically releases the action (see [`InputActionDrain::release`])
+    /// whenever `condition` holds, so it doesn't stay stuck `Active`
+    /// once whatever pours into its drain is itself disabled or torn
+    /// down (e.g. its owning entity despawns, or an input context is
+    /// gated behind a `run_if`) and can no longer pour a release on
+    /// its own.
+    ///
+    /// The release runs in [`InputActionSystem::Primitive`], before
+    /// the action's state is resolved for the frame, so a proper
+    /// `Stopped` event is still emitted through the normal event
+    /// pipeline if the action was `Active`.
+    pub fn auto_release<M>(mut self, condition: impl Condition<M>) -> Self {
+        self.auto_release = Mutex::new(Some(Box::new(IntoSystem::into_system(condition))));
+        self
+    }
 }
 
 impl<A: InputAction> Default for InputActionPlugin<A> {
@@ -51,10 +143,13 @@ impl<A: InputAction> Default for InputActionPlugin<A> {
 
 impl<A: InputAction> Plugin for InputActionPlugin<A> {
     fn build(&self, app: &mut App) {
+        configure_input_action_sets(app);
+
         app.init_resource::<internal::InputActionState<A>>();
         app.init_resource::<internal::InputActionDrain<A>>();
+        app.insert_resource(InputActionProcessors(self.processors.clone()));
 
-        app.add_event::<InputActionUpdated<A>>();
+        app.add_event::<internal::InputActionUpdated<A>>();
 
         app.add_systems(
             PreUpdate,
@@ -63,8 +158,66 @@ impl<A: InputAction> Plugin for InputActionPlugin<A> {
                 write_input_action_events::<A>,
             )
                 .chain()
-                .in_set(InputActionSystem),
+                .in_set(InputActionSystem::Primitive),
         );
+        app.add_systems(Last, clear_input_action_drain::<A>);
+
+        if let Some(condition) = self.auto_release.lock().unwrap().take() {
+            let mut config = release_input_action::<A>
+                .in_set(InputActionSystem::Primitive)
+                .before(update_input_action_state::<A>);
+            config.run_if_dyn(condition);
+            app.add_systems(PreUpdate, config);
+        }
+
+        if self.fixed_update {
+            app.add_event::<internal::FixedInputActionUpdated<A>>();
+
+            app.add_systems(
+                FixedPreUpdate,
+                (
+                    update_input_action_state::<A>,
+                    write_fixed_input_action_events::<A>,
+                )
+                    .chain(),
+            );
+        }
+    }
+}
+
+/// Configures the relative ordering of
+/// [`InputActionSystem::Primitive`], `::Derived` and `::Chord`.
+///
+/// Safe to call more than once per app: `configure_sets` merges
+/// repeated ordering constraints instead of erroring, so every
+/// [`InputActionPlugin`] and
+/// [`DerivedInputActionPlugin`](crate::derived::DerivedInputActionPlugin)
+/// can call this unconditionally.
+pub(crate) fn configure_input_action_sets(app: &mut App) {
+    app.configure_sets(
+        PreUpdate,
+        (
+            InputActionSystem::Primitive,
+            InputActionSystem::Derived,
+            InputActionSystem::Chord,
+        )
+            .chain(),
+    );
+}
+
+/// The registered value-processing pipeline for an action, run by
+/// [`update_input_action_state`] on the value taken from the drain.
+#[derive(Resource)]
+pub(crate) struct InputActionProcessors<A: InputAction>(
+    Vec<Arc<dyn Fn(A) -> Option<A> + Send + Sync>>,
+);
+
+impl<A: InputAction> InputActionProcessors<A> {
+    /// Returns an empty pipeline, for actions that don't go through
+    /// [`InputActionPlugin::with_processor`] (e.g. derived actions,
+    /// which are shaped by their combinator instead).
+    pub(crate) fn empty() -> Self {
+        Self(Vec::new())
     }
 }
 
@@ -80,18 +233,64 @@ impl<A: InputAction> Plugin for InputActionPlugin<A> {
 ///   [`InputActionState::Idle`].
 ///
 /// ### Behavior
-/// - The drain is cleared after its value is read.
+/// - The drain is only **peeked**, not cleared, so that later
+///   schedules in the same frame (e.g. `FixedPreUpdate`, under
+///   [`InputActionPlugin::in_fixed_update`]) still observe this
+///   frame's poured value. See [`clear_input_action_drain`].
+/// - The value is run through the registered processor pipeline (see
+///   [`InputActionPlugin::with_processor`]) before becoming the new
+///   state; a processor returning `None` collapses the action to
+///   [`InputActionState::Idle`] and stops the pipeline early.
+/// - If the drain was released (see [`InputActionDrain::release`]),
+///   the state becomes [`InputActionState::Idle`] regardless of
+///   what's in the drain.
 /// - This system should run **before** any systems that
 ///   depend on the [`InputActionState`].
-fn update_input_action_state<A: InputAction>(
-    mut drain: ResMut<internal::InputActionDrain<A>>,
+pub(crate) fn update_input_action_state<A: InputAction>(
+    drain: Res<internal::InputActionDrain<A>>,
     mut state: ResMut<internal::InputActionState<A>>,
+    processors: Res<InputActionProcessors<A>>,
 ) {
-    *state = drain
-        .take()
-        .map_or(internal::InputActionState::Idle, |state| {
-            internal::InputActionState::Active(state)
-        });
+    let polled = (**drain).clone().and_then(|value| {
+        processors
+            .0
+            .iter()
+            .try_fold(value, |value, processor| processor(value))
+    });
+
+    *state = if drain.released() {
+        internal::InputActionState::Idle
+    } else {
+        polled.map_or(
+            internal::InputActionState::Idle,
+            internal::InputActionState::Active,
+        )
+    };
+}
+
+/// Releases the [`InputActionDrain`] for `A`, so it resolves to
+/// [`InputActionState::Idle`] this frame regardless of what's
+/// already been poured into it.
+///
+/// Registered by [`InputActionPlugin::auto_release`] to run whenever
+/// its condition holds. See [`crate::reset_input_action`] for a
+/// one-off equivalent that can be called from outside the schedule
+/// (e.g. when despawning the entity that owns `A`'s sources).
+fn release_input_action<A: InputAction>(mut drain: ResMut<internal::InputActionDrain<A>>) {
+    drain.release();
+}
+
+/// Clears the [`InputActionDrain`] for the next frame.
+///
+/// This runs in `Last`, once every schedule that may resolve the
+/// drain this frame (`PreUpdate`, and `FixedPreUpdate` under
+/// [`InputActionPlugin::in_fixed_update`]) has already run, so an
+/// action that isn't poured again next frame correctly falls back to
+/// [`InputActionState::Idle`].
+pub(crate) fn clear_input_action_drain<A: InputAction>(
+    mut drain: ResMut<internal::InputActionDrain<A>>,
+) {
+    drain.clear();
 }
 
 /// Writes events based on changes to the [`InputActionState`].
@@ -112,9 +311,47 @@ fn update_input_action_state<A: InputAction>(
 /// - This system should run **after** the [`InputActionState`]
 ///   is updated.
 ///
-fn write_input_action_events<A: InputAction>(
+pub(crate) fn write_input_action_events<A: InputAction>(
+    mut local: Local<Option<A>>,
+    mut event: EventWriter<internal::InputActionUpdated<A>>,
+    state: Res<internal::InputActionState<A>>,
+) {
+    let state = match state.as_ref() {
+        internal::InputActionState::Active(state) => Some(state),
+        internal::InputActionState::Idle => None,
+    };
+
+    match (&*local, state) {
+        (None, None) => {}
+        (None, Some(value)) => {
+            event.send(internal::InputActionUpdated::Started(value.clone()));
+        }
+        (Some(_), None) => {
+            event.send(internal::InputActionUpdated::Stopped);
+        }
+        (Some(previous), Some(next)) => {
+            if previous != next {
+                event.send(internal::InputActionUpdated::Updated(next.clone()));
+            }
+        }
+    };
+
+    *local = state.cloned();
+}
+
+/// Writes [`FixedInputActionReader`](crate::FixedInputActionReader)
+/// events based on changes to the [`InputActionState`].
+///
+/// Identical to [`write_input_action_events`], except it writes into
+/// the dedicated [`internal::FixedInputActionUpdated`] channel
+/// through its own [`Local`] "previous state" tracker, so it can be
+/// registered in `FixedPreUpdate` (by
+/// [`InputActionPlugin::in_fixed_update`]) without racing the
+/// `PreUpdate` pass that feeds
+/// [`InputActionReader`](crate::InputActionReader).
+pub(crate) fn write_fixed_input_action_events<A: InputAction>(
     mut local: Local<Option<A>>,
-    mut event: EventWriter<InputActionUpdated<A>>,
+    mut event: EventWriter<internal::FixedInputActionUpdated<A>>,
     state: Res<internal::InputActionState<A>>,
 ) {
     let state = match state.as_ref() {
@@ -125,14 +362,14 @@ fn write_input_action_events<A: InputAction>(
     match (&*local, state) {
         (None, None) => {}
         (None, Some(value)) => {
-            event.send(InputActionUpdated::Started(value.clone()));
+            event.send(internal::FixedInputActionUpdated::Started(value.clone()));
         }
         (Some(_), None) => {
-            event.send(InputActionUpdated::Stopped);
+            event.send(internal::FixedInputActionUpdated::Stopped);
         }
         (Some(previous), Some(next)) => {
             if previous != next {
-                event.send(InputActionUpdated::Updated(next.clone()));
+                event.send(internal::FixedInputActionUpdated::Updated(next.clone()));
             }
         }
     };
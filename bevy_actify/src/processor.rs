@@ -0,0 +1,105 @@
+//! SPDX-License-Identifier: MIT OR Apache-2.0
+//!
+//! Built-in value processors for axis-like input actions.
+//!
+//! A processor shapes or filters a value taken from an action's
+//! [`InputActionDrain`](crate::InputActionDrain) before it lands in
+//! [`InputActionState`](crate::InputActionState), see
+//! [`InputActionPlugin::with_processor`](crate::InputActionPlugin::with_processor).
+//! Returning `None` collapses the action to `Idle` for this frame,
+//! reusing the existing drain-empty path.
+
+use std::ops::Mul;
+
+use bevy::math::Vec2;
+
+/// Types with a meaningful magnitude, for magnitude-based processors
+/// like [`deadzone`].
+///
+/// Implemented for a scalar axis (`f32`) and a 2D one (`Vec2`), so
+/// the same [`deadzone`] works as an axial deadzone for a trigger
+/// and a radial one (over the vector's length, not an independent
+/// per-axis cutoff) for a stick or mouse delta.
+pub trait Magnitude {
+    /// Returns this value's magnitude.
+    fn magnitude(&self) -> f32;
+}
+
+impl Magnitude for f32 {
+    fn magnitude(&self) -> f32 {
+        self.abs()
+    }
+}
+
+impl Magnitude for Vec2 {
+    fn magnitude(&self) -> f32 {
+        self.length()
+    }
+}
+
+/// Returns a processor that collapses a value to idle when its
+/// magnitude is below `threshold`, and passes it through unchanged
+/// otherwise.
+///
+/// For a vector action (e.g. `Vec2`), this is a *radial* deadzone:
+/// it's the vector's length that's compared against `threshold`, not
+/// each axis independently, so a stick pushed diagonally isn't
+/// unfairly cut off compared to one pushed along a single axis.
+///
+/// Use this to silence a noisy analog stick or trigger that never
+/// quite rests at its zero point.
+pub fn deadzone<A>(threshold: f32) -> impl Fn(A) -> Option<A> + Send + Sync + Clone + 'static
+where
+    A: Magnitude + Clone + Send + Sync + 'static,
+{
+    move |value: A| (value.magnitude() >= threshold).then_some(value)
+}
+
+/// Returns a processor that clamps a value into `[min, max]`.
+///
+/// For a scalar axis (`f32`), this clamps the value itself. For a
+/// vector action ([`Vec2`]), this clamps the vector's *length* into
+/// `[min, max]`, preserving its direction, so a clamped stick still
+/// points the way it was pushed instead of being pulled onto an
+/// axis-aligned box.
+pub fn clamp<A>(min: f32, max: f32) -> impl Fn(A) -> Option<A> + Send + Sync + Clone + 'static
+where
+    A: Clampable + 'static,
+{
+    move |value: A| Some(value.clamp_magnitude(min, max))
+}
+
+/// Returns a processor that scales a value by `factor`, for
+/// adjusting an axis's sensitivity.
+///
+/// Works for both a scalar axis (`f32`) and a vector action
+/// ([`Vec2`]), uniformly scaling every component by `factor`.
+pub fn sensitivity<A>(factor: f32) -> impl Fn(A) -> Option<A> + Send + Sync + Clone + 'static
+where
+    A: Mul<f32, Output = A> + 'static,
+{
+    move |value: A| Some(value * factor)
+}
+
+/// Types that can be clamped to a magnitude range, for
+/// magnitude-based processors like [`clamp`].
+///
+/// Implemented for a scalar axis (`f32`), clamping the value itself,
+/// and a 2D one ([`Vec2`]), clamping its length while preserving
+/// direction.
+pub trait Clampable {
+    /// Clamps this value's magnitude into `[min, max]`.
+    fn clamp_magnitude(self, min: f32, max: f32) -> Self;
+}
+
+impl Clampable for f32 {
+    fn clamp_magnitude(self, min: f32, max: f32) -> Self {
+        self.clamp(min, max)
+    }
+}
+
+impl Clampable for Vec2 {
+    fn clamp_magnitude(self, min: f32, max: f32) -> Self {
+        self.clamp_length(min, max)
+    }
+}
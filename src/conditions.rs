@@ -14,18 +14,14 @@ pub fn input_action_active<A: InputAction>(action: InputActionState<A>) -> bool
 
 /// Returns `true` if the input action [`A`] has just started.
 pub fn input_action_started<A: InputAction>(mut action: InputActionReader<A>) -> bool {
-    let has_started = action
-        .read()
-        .any(|status| matches!(status, InputActionStatus::Started(_)));
+    let has_started = action.started().next().is_some();
     action.clear();
     has_started
 }
 
 /// Returns `true` if the input action [`A`] has just updated.
 pub fn input_action_updated<A: InputAction>(mut action: InputActionReader<A>) -> bool {
-    let has_updated = action
-        .read()
-        .any(|status| matches!(status, InputActionStatus::Updated(_)));
+    let has_updated = action.updated().next().is_some();
     action.clear();
     has_updated
 }
@@ -17,6 +17,8 @@
 //! - **Multi-source Input**: Combine inputs from multiple sources into a
 //!   single action state
 //! - **Conditional Systems**: Feature-gated helpers for common input conditions
+//! - **State Bindings**: Feature-gated helpers to drive Bevy `States`
+//!   transitions from input action lifecycle events
 //!
 //! # Core Concepts
 //!
@@ -40,6 +42,15 @@
 //!   `InputActionSystem`
 //! - Systems that read from `InputActionState` typically run in
 //!   `Update` (after `PreUpdate`)
+//!
+//! # FixedUpdate Support
+//!
+//! By default, lifecycle events are only correct for consumers
+//! running in or after `Update`. Call
+//! `InputActionAppExt::add_input_action_to_fixed` in addition to
+//! `add_input_action` for actions that are also read from systems
+//! in `FixedUpdate`, and read them there with `FixedInputActionReader`
+//! instead of `InputActionReader`.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -47,6 +58,9 @@
 #[cfg(feature = "conditions")]
 pub mod conditions;
 
+#[cfg(feature = "states")]
+pub mod states;
+
 #[cfg(feature = "derive")]
 pub use bevy_actify_derive::InputAction;
 
@@ -55,34 +69,60 @@ pub use conditions::{
     input_action_active, input_action_started, input_action_stopped, input_action_updated,
 };
 
+#[cfg(feature = "states")]
+pub use states::InputActionStateAppExt;
+
 use std::marker::PhantomData;
 
 use bevy::{
-    app::{App, Plugin, PreUpdate, SubApp},
+    app::{App, FixedPreUpdate, Last, Plugin, PreUpdate, SubApp},
     ecs::{
         event::{EventReader, EventWriter},
-        schedule::{IntoSystemConfigs, SystemSet},
-        system::{Local, Res, ResMut, SystemParam},
+        schedule::{Condition, IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+        system::{Local, Res, ResMut, Resource, SystemParam},
+        world::World,
     },
 };
 
-/// Label for systems that update input actions.
+/// Labels for the ordered sub-sets of systems that update
+/// input actions.
+///
+/// The variants run in declaration order, each strictly after
+/// the previous one:
+/// - **`Primitive`**: Actions poured directly by user input
+///   systems (keyboard, gamepad, etc.).
+/// - **`Derived`**: Actions computed from already-resolved
+///   `Primitive` actions, see
+///   [`InputActionAppExt::add_derived_input_action`].
+/// - **`Chord`**: Actions computed from already-resolved
+///   `Derived` actions, see
+///   [`InputActionAppExt::add_chord_input_action`] and the
+///   [`chord!`] macro.
 ///
 /// ### Usage
 /// - Those systems that provide input action state (i.e.,
 ///   pour into [`InputActionDrain`]) should be configured
-///   to run **before** this set.
+///   to run **before** the set of the action they pour into.
 /// - Those systems that read input action state should be
-///   configured to run **after** this set.
+///   configured to run **after** `InputActionSystem::Chord`.
 ///
 /// ### Notes
 /// Since all input action systems run in the `PreUpdate`
 /// stage, the systems that read input action state almost
-/// never have to be explicitly configured to run after this
-/// set, because they are most likely to run in the `Update`
+/// never have to be explicitly configured to run after these
+/// sets, because they are most likely to run in the `Update`
 /// schedule, which already runs *after*.
 #[derive(SystemSet, Hash, PartialEq, Eq, Clone, Debug)]
-pub struct InputActionSystem;
+pub enum InputActionSystem {
+    /// Actions poured directly by user input systems.
+    Primitive,
+
+    /// Actions computed from already-resolved `Primitive` actions.
+    Derived,
+
+    /// Actions computed from already-resolved `Derived` actions.
+    Chord,
+}
 
 /// Provides read-only access to the current state of an
 /// input action.
@@ -116,20 +156,83 @@ pub struct InputActionState<'w, A: InputAction> {
 /// see [`InputActionDrain::pour`].
 ///
 /// ### Behavior
-/// - The drain only retains the **most recent state** poured
-///   into it.
-/// - Any previously poured value is overwritten by the new value.
-/// - The drain is cleared every frame after its value is read
-///   to update the [`InputActionState`].
+/// - The drain accumulates **every** value poured into it
+///   this frame.
+/// - The accumulated contributions are folded into a single
+///   winner by the action's [`MergeStrategy`] (`LastWins` by
+///   default) to produce the final [`InputActionState`].
+/// - The drain is cleared once per frame, in `Last`, after every
+///   schedule that reads it (`PreUpdate`, and `FixedPreUpdate`
+///   when [`InputActionAppExt::add_input_action_to_fixed`] is
+///   used) has had a chance to observe it.
 ///
 /// ### Notes
-/// - If multiple systems pour state into the drain, only the
-///   **last value** poured will be used to update the [`InputActionState`].
+/// - If multiple systems pour state into the drain in the same
+///   frame, which one wins is decided by the action's
+///   [`MergeStrategy`], not simply "whoever ran last".
 #[derive(SystemParam, Debug)]
 pub struct InputActionDrain<'w, A: InputAction> {
     inner: ResMut<'w, internal::InputActionDrain<A>>,
 }
 
+/// Resolves multiple contributions poured into an
+/// [`InputActionDrain`] within the same frame down to a
+/// single winning value.
+///
+/// Registered as a resource alongside the action (see
+/// [`InputActionAppExt::add_input_action_with_strategy`]), so
+/// that when several systems (e.g. keyboard and gamepad) pour
+/// into the same action in one frame, the winner is decided
+/// deliberately instead of being whichever system happened to
+/// run last.
+#[derive(Resource, Debug)]
+pub enum MergeStrategy<A: InputAction> {
+    /// The most recently poured contribution wins. This is the
+    /// default, and matches the drain's original behavior.
+    LastWins,
+
+    /// The first contribution poured this frame wins.
+    FirstWins,
+
+    /// The contribution poured with the highest priority (see
+    /// [`InputActionDrain::pour_with_priority`]) wins, with ties
+    /// broken in favor of whichever was poured last.
+    Priority,
+
+    /// A user-supplied function picks the winner (or `None`) from
+    /// every value poured this frame, e.g. to sum axis values or
+    /// keep the largest-magnitude one.
+    Custom(fn(&[A]) -> Option<A>),
+}
+
+impl<A: InputAction> Default for MergeStrategy<A> {
+    fn default() -> Self {
+        Self::LastWins
+    }
+}
+
+impl<A: InputAction> MergeStrategy<A> {
+    /// Folds every contribution poured this frame down to at
+    /// most one winning value.
+    fn resolve(&self, contributions: &[(A, i32)]) -> Option<A> {
+        match self {
+            Self::LastWins => contributions.last().map(|(value, _)| value.clone()),
+            Self::FirstWins => contributions.first().map(|(value, _)| value.clone()),
+            Self::Priority => contributions
+                .iter()
+                .max_by_key(|(_, priority)| *priority)
+                .map(|(value, _)| value.clone()),
+            Self::Custom(resolve) => {
+                let values: Vec<A> = contributions
+                    .iter()
+                    .map(|(value, _)| value.clone())
+                    .collect();
+                resolve(&values)
+            }
+        }
+    }
+}
+
 /// Represents the status of an input action as read
 /// from an [`InputActionReader`].
 ///
@@ -170,6 +273,20 @@ pub struct InputActionReader<'w, 's, A: InputAction> {
     inner: EventReader<'w, 's, internal::InputActionUpdated<A>>,
 }
 
+/// Reader for input action status updates, from `FixedUpdate`.
+///
+/// Identical to [`InputActionReader`], except it reads the
+/// independent event channel written by the `FixedPreUpdate` pass
+/// registered via [`InputActionAppExt::add_input_action_to_fixed`].
+/// Use this (instead of [`InputActionReader`]) in systems living in
+/// or after `FixedUpdate`, so a transition is observed exactly once
+/// per fixed tick it's relevant to, instead of racing the
+/// `Update`-facing channel.
+#[derive(SystemParam, Debug)]
+pub struct FixedInputActionReader<'w, 's, A: InputAction> {
+    inner: EventReader<'w, 's, internal::FixedInputActionUpdated<A>>,
+}
+
 /// Marker trait for all input actions.
 pub trait InputAction: Send + Sync + Clone + PartialEq + 'static {}
 
@@ -181,23 +298,139 @@ pub trait InputActionAppExt {
     /// required for an input action to fully function
     /// within an app.
     fn add_input_action<A: InputAction>(&mut self);
+
+    /// Adds the input action to the app with a non-default
+    /// [`MergeStrategy`].
+    ///
+    /// Use this instead of [`InputActionAppExt::add_input_action`]
+    /// when more than one source may pour into the action's
+    /// [`InputActionDrain`] in the same frame and the default
+    /// `LastWins` behavior isn't what you want.
+    fn add_input_action_with_strategy<A: InputAction>(&mut self, strategy: MergeStrategy<A>);
+
+    /// Adds FixedUpdate-correct `Started`/`Updated`/`Stopped`
+    /// tracking for an input action.
+    ///
+    /// By default, an [`InputActionReader`] only observes a
+    /// lifecycle transition once per render frame, which is
+    /// wrong for systems living in `FixedUpdate`: when the
+    /// fixed schedule runs more than once per frame the same
+    /// transition is only seen on one of the ticks, and when
+    /// it doesn't run at all a transient press can be missed
+    /// entirely.
+    ///
+    /// This re-resolves the [`InputActionState`] and registers a
+    /// second, independently-tracked event-writing pass in
+    /// `FixedPreUpdate`, writing into its own channel (read via
+    /// [`FixedInputActionReader`]) so fixed-timestep readers observe
+    /// each transition once, however many fixed ticks run in a
+    /// frame, without racing (or doubling up on) whatever an
+    /// ordinary [`InputActionReader`] observes from `PreUpdate`.
+    ///
+    /// The pass only runs on frames with at least one fixed tick: an
+    /// action that turns on and off again within frames in which the
+    /// fixed schedule doesn't run is not observed by fixed readers.
+    ///
+    /// ### Notes
+    /// - [`InputActionAppExt::add_input_action`] must be called
+    ///   first to register the action's state and events; this
+    ///   method only adds the additional `FixedPreUpdate` pass.
+    /// - Use [`FixedInputActionReader`], not [`InputActionReader`],
+    ///   to read this action's lifecycle from `FixedUpdate`.
+    fn add_input_action_to_fixed<A: InputAction>(&mut self);
+
+    /// Adds a derived action, computed every frame from other
+    /// already-registered actions.
+    ///
+    /// `system` is scheduled in `InputActionSystem::Derived`,
+    /// after every `InputActionSystem::Primitive` action has
+    /// resolved its state for the frame, and before `Out`'s own
+    /// state is resolved from whatever it pours. No order is set
+    /// between two `Derived` actions, so reading another `Derived`
+    /// action may see its state from the previous frame. It should read the
+    /// [`InputActionState`] of whichever actions it depends on
+    /// and pour into `Out`'s [`InputActionDrain`].
+    ///
+    /// For a derived action that is simply "active when several
+    /// other actions are all active", see the [`chord!`] macro.
+    fn add_derived_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>);
+
+    /// Adds a chord action, computed every frame from other
+    /// already-resolved `Derived` actions.
+    ///
+    /// Identical to
+    /// [`InputActionAppExt::add_derived_input_action`], except
+    /// `system` is scheduled in `InputActionSystem::Chord`, so it
+    /// may additionally read the state of `Derived` actions.
+    fn add_chord_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>);
+
+    /// Resets `A` to [`InputActionState::Idle`] whenever `condition` holds,
+    /// so a held action doesn't linger once its source stops running.
+    ///
+    /// This is useful when the systems that pour into `A`'s
+    /// [`InputActionDrain`] are themselves disabled (e.g. gated
+    /// behind a `run_if`, or removed with their owning entity) and
+    /// so can no longer pour a `release` to let it go idle on their
+    /// own.
+    ///
+    /// The reset is applied in `InputActionSystem::Primitive`,
+    /// before `A`'s state is resolved for the frame, so it takes
+    /// effect on the same tick `condition` first holds.
+    fn add_auto_release_input_action<A: InputAction, M>(&mut self, condition: impl Condition<M>);
 }
 
 impl InputActionAppExt for SubApp {
     fn add_input_action<A: InputAction>(&mut self) {
-        self.init_resource::<internal::InputActionState<A>>();
-        self.init_resource::<internal::InputActionDrain<A>>();
+        self.add_input_action_with_strategy::<A>(MergeStrategy::default());
+    }
 
-        self.add_event::<internal::InputActionUpdated<A>>();
+    fn add_input_action_with_strategy<A: InputAction>(&mut self, strategy: MergeStrategy<A>) {
+        configure_input_action_sets(self);
+        register_input_action::<A>(self, strategy, InputActionSystem::Primitive);
+    }
 
+    fn add_input_action_to_fixed<A: InputAction>(&mut self) {
+        self.add_event::<internal::FixedInputActionUpdated<A>>();
         self.add_systems(
-            PreUpdate,
+            FixedPreUpdate,
             (
                 update_input_action_state::<A>,
-                write_input_action_events::<A>,
+                write_fixed_input_action_events::<A>,
             )
                 .chain()
-                .in_set(InputActionSystem),
+                .in_set(InputActionSystem::Primitive),
+        );
+    }
+
+    fn add_derived_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>) {
+        configure_input_action_sets(self);
+        register_input_action::<Out>(self, MergeStrategy::default(), InputActionSystem::Derived);
+        self.add_systems(
+            PreUpdate,
+            system
+                .in_set(InputActionSystem::Derived)
+                .before(update_input_action_state::<Out>),
+        );
+    }
+
+    fn add_chord_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>) {
+        configure_input_action_sets(self);
+        register_input_action::<Out>(self, MergeStrategy::default(), InputActionSystem::Chord);
+        self.add_systems(
+            PreUpdate,
+            system
+                .in_set(InputActionSystem::Chord)
+                .before(update_input_action_state::<Out>),
+        );
+    }
+
+    fn add_auto_release_input_action<A: InputAction, M>(&mut self, condition: impl Condition<M>) {
+        self.add_systems(
+            PreUpdate,
+            release_input_action::<A>
+                .in_set(InputActionSystem::Primitive)
+                .before(update_input_action_state::<A>)
+                .run_if(condition),
         );
     }
 }
@@ -206,6 +439,71 @@ impl InputActionAppExt for App {
     fn add_input_action<A: InputAction>(&mut self) {
         self.main_mut().add_input_action::<A>();
     }
+
+    fn add_input_action_with_strategy<A: InputAction>(&mut self, strategy: MergeStrategy<A>) {
+        self.main_mut()
+            .add_input_action_with_strategy::<A>(strategy);
+    }
+
+    fn add_input_action_to_fixed<A: InputAction>(&mut self) {
+        self.main_mut().add_input_action_to_fixed::<A>();
+    }
+
+    fn add_derived_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>) {
+        self.main_mut().add_derived_input_action::<Out, M>(system);
+    }
+
+    fn add_chord_input_action<Out: InputAction, M>(&mut self, system: impl IntoSystemConfigs<M>) {
+        self.main_mut().add_chord_input_action::<Out, M>(system);
+    }
+
+    fn add_auto_release_input_action<A: InputAction, M>(&mut self, condition: impl Condition<M>) {
+        self.main_mut()
+            .add_auto_release_input_action::<A, M>(condition);
+    }
+}
+
+/// Configures the relative ordering of
+/// [`InputActionSystem::Primitive`], `::Derived` and `::Chord`.
+///
+/// Safe to call more than once per app: `configure_sets` merges
+/// repeated ordering constraints instead of erroring.
+fn configure_input_action_sets(app: &mut SubApp) {
+    app.configure_sets(
+        PreUpdate,
+        (
+            InputActionSystem::Primitive,
+            InputActionSystem::Derived,
+            InputActionSystem::Chord,
+        )
+            .chain(),
+    );
+}
+
+/// Registers the resources, events and update/clear systems
+/// shared by every kind of input action, in the given sub-set.
+fn register_input_action<A: InputAction>(
+    app: &mut SubApp,
+    strategy: MergeStrategy<A>,
+    set: InputActionSystem,
+) {
+    app.init_resource::<internal::InputActionState<A>>();
+    app.init_resource::<internal::InputActionDrain<A>>();
+    app.insert_resource(strategy);
+
+    app.add_event::<internal::InputActionUpdated<A>>();
+
+    app.add_systems(
+        PreUpdate,
+        (
+            update_input_action_state::<A>,
+            write_input_action_events::<A>,
+        )
+            .chain()
+            .in_set(set),
+    );
+
+    app.add_systems(Last, clear_input_action_drain::<A>);
 }
 
 impl<A: InputAction> InputActionState<'_, A> {
@@ -231,8 +529,19 @@ impl<A: InputAction> InputActionState<'_, A> {
     ///   avoid calling this method repeatedly if you only need
     ///   to check the active status.
     pub fn state(&self) -> Option<A> {
+        self.get().cloned()
+    }
+
+    /// Returns a reference to the current state of the input
+    /// action, without cloning it.
+    ///
+    /// Behaves exactly like [`InputActionState::state`], except it
+    /// borrows from the underlying resource instead of cloning, so
+    /// it's free to call even for actions whose value is expensive
+    /// to clone (strings, vectors of touch points, ...).
+    pub fn get(&self) -> Option<&A> {
         match self.inner.as_ref() {
-            internal::InputActionState::Active(state) => Some(state.clone()),
+            internal::InputActionState::Active(state) => Some(state),
             internal::InputActionState::Idle => None,
         }
     }
@@ -246,21 +555,50 @@ impl<A: InputAction> InputActionDrain<'_, A> {
     /// action as provided by a specific source (e.g., keyboard,
     /// gamepad, or other input systems).
     ///
-    /// ### Behavior
-    /// - The drain only retains the **most recent state** that was
-    ///   poured into it.
-    /// - Any previously poured state is overwritten by the new state.
-    /// - The drain is cleared every frame after its state is read
-    ///   to update the `InputActionState`.
+    /// Equivalent to [`InputActionDrain::pour_with_priority`] with
+    /// priority `0`.
     ///
     /// ### Notes
     /// - This method is typically called by systems that provide
     ///   input action values (e.g., keyboard or gamepad input systems).
-    /// - If multiple systems pour states into the drain, only
-    ///   the **last state** poured will be used to update the
-    ///   `InputActionState`.
+    /// - If multiple systems pour states into the drain in the
+    ///   same frame, which one is used to update the
+    ///   `InputActionState` is decided by the action's
+    ///   [`MergeStrategy`], not simply "the last one poured".
     pub fn pour(&mut self, state: A) {
-        self.inner.replace(state);
+        self.pour_with_priority(state, 0);
+    }
+
+    /// Pours a state into the drain with an explicit priority,
+    /// for use with [`MergeStrategy::Priority`].
+    ///
+    /// ### Behavior
+    /// - The drain accumulates every state poured this frame,
+    ///   alongside the priority it was poured with.
+    /// - The drain is cleared once per frame, after every schedule
+    ///   that reads it has had a chance to observe it.
+    pub fn pour_with_priority(&mut self, state: A, priority: i32) {
+        self.inner.push(state, priority);
+    }
+
+    /// Forces the action to resolve to [`InputActionState::Idle`]
+    /// this frame, even if something was (or still will be)
+    /// poured into the drain.
+    ///
+    /// Use this when a producing subsystem is torn down (a
+    /// controller disconnects, an input-owning entity despawns, a
+    /// gameplay mode is switched off, ...) so the action doesn't
+    /// stay stuck `Active` forever. The normal event pipeline still
+    /// runs afterwards, so a proper `Stopped` event is emitted if
+    /// the action was `Active` last frame.
+    ///
+    /// See also [`reset_input_action`] for releasing an action from
+    /// outside a system (e.g. exclusive teardown code with direct
+    /// `World` access), and
+    /// [`InputActionAppExt::add_auto_release_input_action`] for
+    /// driving this automatically from a condition.
+    pub fn release(&mut self) {
+        self.inner.release();
     }
 }
 
@@ -274,6 +612,34 @@ impl<A: InputAction> InputActionReader<'_, '_, A> {
         })
     }
 
+    /// Returns the values of every `Started` event read this call,
+    /// without cloning them.
+    ///
+    /// Equivalent to filtering [`InputActionReader::read`] down to
+    /// [`InputActionStatus::Started`], for condition helpers like
+    /// [`crate::conditions::input_action_started`] that need to
+    /// inspect the value instead of just checking it arrived.
+    pub fn started(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Started(value) => Some(value),
+            InputActionStatus::Updated(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// Returns the values of every `Updated` event read this call,
+    /// without cloning them.
+    ///
+    /// Equivalent to filtering [`InputActionReader::read`] down to
+    /// [`InputActionStatus::Updated`], for condition helpers like
+    /// [`crate::conditions::input_action_updated`] that need to
+    /// inspect the value instead of just checking it arrived.
+    pub fn updated(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Updated(value) => Some(value),
+            InputActionStatus::Started(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
     /// see [`EventReader::is_empty`](bevy::ecs::event::EventReader::is_empty).
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
@@ -285,30 +651,123 @@ impl<A: InputAction> InputActionReader<'_, '_, A> {
     }
 }
 
-/// Updates the [`InputActionState`] based on the value
-/// in the [`InputActionDrain`].
+impl<A: InputAction> FixedInputActionReader<'_, '_, A> {
+    /// see [`EventReader::read`](bevy::ecs::event::EventReader::read).
+    pub fn read(&mut self) -> impl ExactSizeIterator<Item = InputActionStatus<A>> {
+        self.inner.read().map(|event| match event {
+            internal::FixedInputActionUpdated::Started(state) => InputActionStatus::Started(state),
+            internal::FixedInputActionUpdated::Updated(state) => InputActionStatus::Updated(state),
+            internal::FixedInputActionUpdated::Stopped => InputActionStatus::Stopped,
+        })
+    }
+
+    /// Returns the values of every `Started` event read this call,
+    /// without cloning them.
+    pub fn started(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Started(value) => Some(value),
+            InputActionStatus::Updated(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// Returns the values of every `Updated` event read this call,
+    /// without cloning them.
+    pub fn updated(&mut self) -> impl Iterator<Item = &A> {
+        self.read().filter_map(|status| match status {
+            InputActionStatus::Updated(value) => Some(value),
+            InputActionStatus::Started(_) | InputActionStatus::Stopped => None,
+        })
+    }
+
+    /// see [`EventReader::is_empty`](bevy::ecs::event::EventReader::is_empty).
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// see [`EventReader::clear`](bevy::ecs::event::EventReader::clear).
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+/// Updates the [`InputActionState`] based on the contributions
+/// accumulated in the [`InputActionDrain`].
 ///
-/// This system reads the current value from the
-/// [`InputActionDrain`] and updates the [`InputActionState`]
+/// This system folds every value poured this frame through the
+/// action's [`MergeStrategy`] and updates the [`InputActionState`]
 /// accordingly:
-/// - If the drain contains a value, the state becomes
+/// - If a contribution wins, the state becomes
 ///   [`InputActionState::Active`].
-/// - If the drain is empty, the state becomes
-///   [`InputActionState::Idle`].
+/// - If nothing was poured (or the strategy yields nothing), the
+///   state becomes [`InputActionState::Idle`].
 ///
 /// ### Behavior
-/// - The drain is cleared after its value is read.
+/// - The drain is only **peeked**, not cleared, so that later
+///   schedules in the same frame (e.g. `FixedPreUpdate`) still
+///   observe this frame's contributions. See
+///   [`clear_input_action_drain`].
 /// - This system should run **before** any systems that
 ///   depend on the [`InputActionState`].
 fn update_input_action_state<A: InputAction>(
-    mut drain: ResMut<internal::InputActionDrain<A>>,
+    drain: Res<internal::InputActionDrain<A>>,
+    strategy: Res<MergeStrategy<A>>,
     mut state: ResMut<internal::InputActionState<A>>,
 ) {
-    *state = drain
-        .take()
-        .map_or(internal::InputActionState::Idle, |state| {
-            internal::InputActionState::Active(state)
-        });
+    *state = if drain.released() {
+        internal::InputActionState::Idle
+    } else {
+        strategy.resolve(drain.contributions()).map_or(
+            internal::InputActionState::Idle,
+            internal::InputActionState::Active,
+        )
+    };
+}
+
+/// Clears the [`InputActionDrain`] for the next frame.
+///
+/// This runs in `Last`, once every schedule that may read the
+/// drain this frame (`PreUpdate`, and `FixedPreUpdate` when
+/// [`InputActionAppExt::add_input_action_to_fixed`] is used) has
+/// already run, so an action that isn't poured again next frame
+/// correctly falls back to [`InputActionState::Idle`].
+fn clear_input_action_drain<A: InputAction>(mut drain: ResMut<internal::InputActionDrain<A>>) {
+    drain.clear();
+}
+
+/// Releases the [`InputActionDrain`] for `A`, so it resolves to
+/// [`InputActionState::Idle`] on the current frame regardless of
+/// what's already been poured into it.
+///
+/// Registered by [`InputActionAppExt::add_auto_release_input_action`]
+/// to run whenever its condition holds. See [`reset_input_action`]
+/// for a one-off equivalent that can be called from outside the
+/// schedule (e.g. when despawning the entity that owns `A`'s
+/// sources).
+fn release_input_action<A: InputAction>(mut drain: ResMut<internal::InputActionDrain<A>>) {
+    drain.release();
+}
+
+/// Releases the [`InputActionDrain`] for `A`, so it resolves to
+/// [`InputActionState::Idle`] the next time its state is resolved in
+/// the current frame, regardless of what's been poured into it. The
+/// release is cleared with the drain in `Last`, so when called after
+/// that resolution (e.g. from `Update`) it has no effect on later
+/// frames.
+///
+/// This is the one-off, imperative counterpart to
+/// [`InputActionAppExt::add_auto_release_input_action`], for call
+/// sites that aren't naturally expressed as a `run_if` condition,
+/// such as a one-time cleanup when despawning the entity that owns
+/// `A`'s input sources.
+///
+/// Does nothing if `A` hasn't been registered with any of
+/// [`InputActionAppExt::add_input_action`],
+/// [`InputActionAppExt::add_derived_input_action`], or
+/// [`InputActionAppExt::add_chord_input_action`].
+pub fn reset_input_action<A: InputAction>(world: &mut World) {
+    if let Some(mut drain) = world.get_resource_mut::<internal::InputActionDrain<A>>() {
+        drain.release();
+    }
 }
 
 /// Writes events based on changes to the [`InputActionState`].
@@ -357,9 +816,71 @@ fn write_input_action_events<A: InputAction>(
     *local = state.cloned();
 }
 
-mod internal {
-    use std::ops::{Deref, DerefMut};
+/// Writes [`FixedInputActionReader`] events based on changes to the
+/// [`InputActionState`].
+///
+/// Identical to [`write_input_action_events`], except it writes into
+/// the dedicated [`internal::FixedInputActionUpdated`] channel
+/// through its own [`Local`] "previous state" tracker, so it can be
+/// registered in `FixedPreUpdate` (by
+/// [`InputActionAppExt::add_input_action_to_fixed`]) without racing
+/// the `PreUpdate` pass that feeds [`InputActionReader`].
+fn write_fixed_input_action_events<A: InputAction>(
+    mut local: Local<Option<A>>,
+    mut event: EventWriter<internal::FixedInputActionUpdated<A>>,
+    state: Res<internal::InputActionState<A>>,
+) {
+    let state = match state.as_ref() {
+        internal::InputActionState::Active(state) => Some(state),
+        internal::InputActionState::Idle => None,
+    };
+
+    match (&*local, state) {
+        (None, None) => {}
+        (None, Some(value)) => {
+            event.send(internal::FixedInputActionUpdated::Started(value.clone()));
+        }
+        (Some(_), None) => {
+            event.send(internal::FixedInputActionUpdated::Stopped);
+        }
+        (Some(previous), Some(next)) => {
+            if previous != next {
+                event.send(internal::FixedInputActionUpdated::Updated(next.clone()));
+            }
+        }
+    };
+
+    *local = state.cloned();
+}
 
+/// Registers a chord action that is active, with the given
+/// default value, only when every listed input action is active.
+///
+/// Expands to a call to
+/// [`InputActionAppExt::add_chord_input_action`], so it is subject
+/// to the same ordering: the listed inputs must already be
+/// registered (as `Primitive` or `Derived` actions) before the
+/// chord is added, and `Out` must implement [`Default`].
+///
+/// ### Example
+/// ```ignore
+/// chord!(app, Dash, [sprint: Sprint, jump: Jump]);
+/// ```
+#[macro_export]
+macro_rules! chord {
+    ($app:expr, $out:ty, [$($name:ident : $input:ty),+ $(,)?]) => {
+        $crate::InputActionAppExt::add_chord_input_action::<$out, _>(
+            $app,
+            move |mut drain: $crate::InputActionDrain<$out>, $($name: $crate::InputActionState<$input>),+| {
+                if $($name.is_active())&&+ {
+                    drain.pour(<$out as ::std::default::Default>::default());
+                }
+            },
+        )
+    };
+}
+
+mod internal {
     use bevy::ecs::{event::Event, system::Resource};
 
     use crate::InputAction;
@@ -382,20 +903,26 @@ mod internal {
     /// Temporary storage for the current input action state.
     ///
     /// This resource is used by *producing systems* to write
-    /// the current state of an input action. Only the most
-    /// recently written value is retained, and older values
-    /// are discarded.
+    /// the current state of an input action. Every contribution
+    /// poured this frame is retained, alongside the priority it
+    /// was poured with, until the action's `MergeStrategy`
+    /// resolves them into a single value.
     ///
-    /// The value in the drain is used to update the [`InputActionState`]
-    /// at the end of each frame.
+    /// The contributions in the drain are used to update the
+    /// [`InputActionState`] every frame.
     ///
     /// ### Behavior
-    /// - If multiple systems write to the drain, only the
-    ///   **last value** written will be used.
-    /// - The drain is automatically cleared after its value
-    ///   is read to update the [`InputActionState`].
+    /// - The drain is only peeked while updating [`InputActionState`];
+    ///   it is explicitly cleared via [`InputActionDrain::clear`]
+    ///   once every schedule that reads it has run for the frame.
+    /// - If [`InputActionDrain::release`] was called this frame,
+    ///   any contributions are ignored and the action resolves to
+    ///   `Idle`.
     #[derive(Resource, Debug)]
-    pub struct InputActionDrain<A: InputAction>(Option<A>);
+    pub struct InputActionDrain<A: InputAction> {
+        contributions: Vec<(A, i32)>,
+        released: bool,
+    }
 
     /// Input action update event.
     ///
@@ -408,6 +935,19 @@ mod internal {
         Stopped,
     }
 
+    /// Input action update event, for `FixedUpdate` readers.
+    ///
+    /// Same payload as [`InputActionUpdated`], but registered as its
+    /// own event channel so an [`crate::InputActionReader`] and a
+    /// [`crate::FixedInputActionReader`] for the same action never
+    /// double up on (or race for) the same transition.
+    #[derive(Event, Debug)]
+    pub enum FixedInputActionUpdated<A: InputAction> {
+        Started(A),
+        Updated(A),
+        Stopped,
+    }
+
     impl<A: InputAction> Default for InputActionState<A> {
         fn default() -> Self {
             Self::Idle
@@ -416,21 +956,43 @@ mod internal {
 
     impl<A: InputAction> Default for InputActionDrain<A> {
         fn default() -> Self {
-            Self(None)
+            Self {
+                contributions: Vec::new(),
+                released: false,
+            }
         }
     }
 
-    impl<A: InputAction> Deref for InputActionDrain<A> {
-        type Target = Option<A>;
+    impl<A: InputAction> InputActionDrain<A> {
+        /// Pushes a contribution, poured with the given priority,
+        /// onto the drain.
+        pub(crate) fn push(&mut self, state: A, priority: i32) {
+            self.contributions.push((state, priority));
+        }
+
+        /// Returns every contribution poured into the drain this
+        /// frame, without clearing it.
+        pub(crate) fn contributions(&self) -> &[(A, i32)] {
+            &self.contributions
+        }
 
-        fn deref(&self) -> &Self::Target {
-            &self.0
+        /// Forces the action to resolve to `Idle` this frame,
+        /// regardless of what was (or still will be) poured.
+        pub(crate) fn release(&mut self) {
+            self.released = true;
+        }
+
+        /// Returns whether [`InputActionDrain::release`] was called
+        /// this frame.
+        pub(crate) fn released(&self) -> bool {
+            self.released
         }
-    }
 
-    impl<A: InputAction> DerefMut for InputActionDrain<A> {
-        fn deref_mut(&mut self) -> &mut Self::Target {
-            &mut self.0
+        /// Clears every contribution poured into the drain this
+        /// frame, and resets the released flag.
+        pub(crate) fn clear(&mut self) {
+            self.contributions.clear();
+            self.released = false;
         }
     }
 }
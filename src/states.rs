@@ -0,0 +1,94 @@
+//! SPDX-License-Identifier: MIT OR Apache-2.0
+//!
+//! Bindings from input action lifecycle events to Bevy `States`
+//! transitions.
+
+use bevy::{
+    app::{App, PreUpdate, SubApp},
+    ecs::{
+        schedule::IntoSystemConfigs,
+        system::{Local, ResMut},
+    },
+    state::state::{FreelyMutableState, NextState},
+};
+
+use crate::{InputAction, InputActionReader, InputActionStatus, InputActionSystem};
+
+/// Extension trait for [`App`] and [`SubApp`] that binds an input
+/// action's lifecycle directly to a `States` transition.
+pub trait InputActionStateAppExt {
+    /// Registers `map` to translate `A`'s lifecycle into `NextState<S>`
+    /// transitions, so `S` advances automatically as `A` starts,
+    /// updates, and stops, with no hand-written glue system.
+    ///
+    /// `map` is called once per lifecycle event read this frame, and
+    /// whenever it returns `Some(state)`, `state` is requested via
+    /// [`NextState::set`]. For value-carrying actions, inspect the
+    /// borrowed value on [`InputActionStatus::Updated`] (or
+    /// [`InputActionStatus::Started`]) to pick the target state, e.g.
+    /// mapping a "select menu tab" action's index to the matching tab
+    /// state.
+    ///
+    /// ### Behavior
+    /// - Runs after `InputActionSystem`, so `map` sees this frame's
+    ///   fully-resolved lifecycle, including `Derived` and `Chord`
+    ///   actions.
+    /// - Debounced: requesting the same state twice in a row (e.g.
+    ///   two `Updated` events that map to the same `S`) only calls
+    ///   `NextState::set` once, so it doesn't spuriously re-trigger
+    ///   `OnEnter(S)`. The debounce resets whenever `map` yields
+    ///   `None` (e.g. on `Stopped`), so leaving `S` and then
+    ///   re-entering it later still requests it again.
+    ///
+    /// ### Notes
+    /// - `S` must already be registered with `App::init_state` (or
+    ///   equivalent); this method only adds the binding system.
+    fn add_input_action_state_binding<A, S>(
+        &mut self,
+        map: impl Fn(InputActionStatus<A>) -> Option<S> + Send + Sync + 'static,
+    ) where
+        A: InputAction,
+        S: FreelyMutableState;
+}
+
+impl InputActionStateAppExt for SubApp {
+    fn add_input_action_state_binding<A, S>(
+        &mut self,
+        map: impl Fn(InputActionStatus<A>) -> Option<S> + Send + Sync + 'static,
+    ) where
+        A: InputAction,
+        S: FreelyMutableState,
+    {
+        self.add_systems(
+            PreUpdate,
+            (move |mut reader: InputActionReader<A>,
+                   mut next_state: ResMut<NextState<S>>,
+                   mut last: Local<Option<S>>| {
+                for status in reader.read() {
+                    match map(status) {
+                        Some(state) => {
+                            if last.as_ref() != Some(&state) {
+                                next_state.set(state.clone());
+                                *last = Some(state);
+                            }
+                        }
+                        None => *last = None,
+                    }
+                }
+            })
+            .after(InputActionSystem::Chord),
+        );
+    }
+}
+
+impl InputActionStateAppExt for App {
+    fn add_input_action_state_binding<A, S>(
+        &mut self,
+        map: impl Fn(InputActionStatus<A>) -> Option<S> + Send + Sync + 'static,
+    ) where
+        A: InputAction,
+        S: FreelyMutableState,
+    {
+        self.main_mut().add_input_action_state_binding::<A, S>(map);
+    }
+}